@@ -61,6 +61,35 @@ impl<const PAGE: usize> EarlyAllocator<PAGE> {
     fn is_initialized(&self) -> bool {
         self.start.load(Ordering::Relaxed) != 0 && self.end.load(Ordering::Relaxed) != 0
     }
+
+    /// 检查分配器内部不变量是否全部成立。
+    ///
+    /// 依次检查 `start <= b_pos`、`b_pos <= p_pos`、`p_pos <= end` 以及
+    /// `used_bytes + available_bytes + (end - p_pos) == total_bytes`，
+    /// 返回第一个被违反的不变量的描述。
+    pub fn audit(&self) -> Result<(), &'static str> {
+        let start = self.start.load(Ordering::Relaxed);
+        let end = self.end.load(Ordering::Relaxed);
+        let b_pos = self.b_pos.load(Ordering::Relaxed);
+        let p_pos = self.p_pos.load(Ordering::Relaxed);
+
+        if start > b_pos {
+            return Err("invariant violated: start <= b_pos");
+        }
+        if b_pos > p_pos {
+            return Err("invariant violated: b_pos <= p_pos");
+        }
+        if p_pos > end {
+            return Err("invariant violated: p_pos <= end");
+        }
+        let pages_used = end - p_pos;
+        if self.used_bytes() + self.available_bytes() + pages_used != self.total_bytes() {
+            return Err(
+                "invariant violated: used_bytes + available_bytes + (end - p_pos) == total_bytes",
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<const PAGE: usize> BaseAllocator for EarlyAllocator<PAGE> {
@@ -256,3 +285,39 @@ impl<const PAGE: usize> PageAllocator for EarlyAllocator<PAGE> {
         align_down(self.available_bytes(), Self::PAGE_SIZE) / Self::PAGE_SIZE
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: usize = 0x1000;
+
+    fn new_allocator() -> EarlyAllocator<PAGE> {
+        let mut alloc = EarlyAllocator::<PAGE>::new();
+        alloc.init(0x10_0000, 16 * PAGE);
+        alloc
+    }
+
+    #[test]
+    fn audit_healthy_allocator() {
+        let mut alloc = new_allocator();
+        assert_eq!(alloc.audit(), Ok(()));
+
+        alloc
+            .alloc(Layout::from_size_align(24, 8).unwrap())
+            .unwrap();
+        alloc.alloc_pages(2, 12).unwrap();
+        assert_eq!(alloc.audit(), Ok(()));
+    }
+
+    #[test]
+    fn audit_reports_violated_invariant() {
+        let alloc = new_allocator();
+        // 人为破坏: 让字节指针越过页指针
+        let p_pos = alloc.p_pos.load(Ordering::Relaxed);
+        alloc.b_pos.store(p_pos + PAGE, Ordering::Relaxed);
+
+        let err = alloc.audit().unwrap_err();
+        assert!(err.contains("b_pos <= p_pos"), "unexpected message: {err}");
+    }
+}
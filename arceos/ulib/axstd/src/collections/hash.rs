@@ -114,4 +114,127 @@ where
     pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
         self.buckets.iter().flat_map(|bucket| bucket.iter().map(|(k, v)| (k, v)))
     }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if self.count > self.buckets.len() * 2 {
+            self.resize();
+        }
+        let mut hasher = SimpleHasher::new(self.hasher);
+        key.hash(&mut hasher);
+        let index = (hasher.finish() % self.buckets.len() as u64) as usize;
+        match self.buckets[index]
+            .iter()
+            .position(|(existing_key, _)| existing_key == &key)
+        {
+            Some(pos) => Entry::Occupied(OccupiedEntry {
+                map: self,
+                index,
+                pos,
+            }),
+            None => Entry::Vacant(VacantEntry {
+                map: self,
+                key,
+                index,
+            }),
+        }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut HashMap<K, V>,
+    index: usize,
+    pos: usize,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn get(&self) -> &V {
+        &self.map.buckets[self.index][self.pos].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.buckets[self.index][self.pos].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.buckets[self.index][self.pos].1
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.map.count -= 1;
+        self.map.buckets[self.index].swap_remove(self.pos).1
+    }
+}
+
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut HashMap<K, V>,
+    key: K,
+    index: usize,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn insert(self, value: V) -> &'a mut V {
+        let bucket = &mut self.map.buckets[self.index];
+        bucket.push((self.key, value));
+        self.map.count += 1;
+        &mut bucket.last_mut().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_vacant_insert() {
+        let mut map = HashMap::new();
+        match map.entry(1) {
+            Entry::Occupied(_) => panic!("unexpected occupied entry"),
+            Entry::Vacant(entry) => *entry.insert(10) += 1,
+        }
+        assert_eq!(map.get(&1), Some(&11));
+        assert_eq!(map.count, 1);
+    }
+
+    #[test]
+    fn entry_occupied_access() {
+        let mut map = HashMap::new();
+        map.insert(1, 10);
+        match map.entry(1) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(*entry.get(), 10);
+                *entry.get_mut() += 1;
+                assert_eq!(entry.insert(20), 11);
+                *entry.into_mut() += 1;
+            }
+            Entry::Vacant(_) => panic!("unexpected vacant entry"),
+        }
+        assert_eq!(map.get(&1), Some(&21));
+        assert_eq!(map.count, 1);
+    }
+
+    #[test]
+    fn entry_occupied_remove() {
+        let mut map = HashMap::new();
+        map.insert(1, 10);
+        map.insert(2, 20);
+        match map.entry(1) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 10),
+            Entry::Vacant(_) => panic!("unexpected vacant entry"),
+        }
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&2), Some(&20));
+        assert_eq!(map.count, 1);
+        assert!(matches!(map.entry(1), Entry::Vacant(_)));
+    }
 }
\ No newline at end of file
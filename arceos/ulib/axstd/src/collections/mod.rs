@@ -2,4 +2,8 @@ mod hash;
 
 pub use alloc::collections::*;
 
-pub use hash::HashMap;
\ No newline at end of file
+pub use hash::HashMap;
+
+pub mod hash_map {
+    pub use super::hash::{Entry, HashMap, OccupiedEntry, VacantEntry};
+}
\ No newline at end of file